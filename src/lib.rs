@@ -91,6 +91,22 @@
 //! icon.notify_set_version()?;  // Apply the version setting
 //! ```
 //!
+//! ### Showing a Balloon Notification
+//!
+//! ```rust,no_run,ignore
+//! use windows::Win32::UI::Shell::NIIF_INFO;
+//!
+//! let icon = NotifyIcon::new()
+//!     .window_handle(hwnd)
+//!     .tip("My Application")
+//!     .icon(icon_handle)
+//!     .info("The operation completed successfully.")
+//!     .info_title("Success")
+//!     .info_flags(NIIF_INFO);
+//!
+//! icon.notify_modify()?;
+//! ```
+//!
 //! ### Modifying Existing Icons
 //!
 //! ```rust,no_run,ignore
@@ -136,12 +152,15 @@
 
 use windows::{
     Win32::{
-        Foundation::{FALSE, HWND},
+        Foundation::{FALSE, HWND, LPARAM, RECT, WPARAM},
         UI::{
             Shell::{
-                NIF_GUID, NIF_ICON, NIF_MESSAGE, NIF_SHOWTIP, NIF_TIP, NIM_ADD, NIM_DELETE,
-                NIM_MODIFY, NIM_SETFOCUS, NIM_SETVERSION, NOTIFY_ICON_DATA_FLAGS,
-                NOTIFY_ICON_MESSAGE, NOTIFYICONDATAW, Shell_NotifyIconW,
+                NIF_GUID, NIF_ICON, NIF_INFO, NIF_MESSAGE, NIF_SHOWTIP, NIF_STATE, NIF_TIP,
+                NIM_ADD, NIM_DELETE, NIM_MODIFY, NIM_SETFOCUS, NIM_SETVERSION,
+                NIN_BALLOONHIDE, NIN_BALLOONSHOW, NIN_BALLOONTIMEOUT, NIN_BALLOONUSERCLICK,
+                NIN_KEYSELECT, NIN_POPUPCLOSE, NIN_POPUPOPEN, NIN_SELECT, NOTIFY_ICON_DATA_FLAGS,
+                NOTIFY_ICON_INFOTIP_FLAGS, NOTIFY_ICON_MESSAGE, NOTIFY_ICON_STATE, NOTIFYICONDATAW,
+                NOTIFYICONIDENTIFIER, Shell_NotifyIconGetRect, Shell_NotifyIconW,
             },
             WindowsAndMessaging::HICON,
         },
@@ -282,6 +301,117 @@ impl NotifyIcon {
         self.flag(NIF_ICON)
     }
 
+    /// Sets the balloon notification text.
+    ///
+    /// This is the main text displayed in the body of the balloon/toast
+    /// notification. The text is converted to UTF-16 format and truncated if
+    /// it exceeds the maximum length. Automatically sets the [NIF_INFO] flag.
+    ///
+    /// Passing an empty string removes the balloon notification described by
+    /// the current data if one is visible.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The balloon notification text as any type that can be
+    ///   converted into a String
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    pub fn info(mut self, s: impl Into<String>) -> Self {
+        let s = s.into();
+        let info_utf16 = s.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
+        let max_len = self.data.szInfo.len() - 1;
+        if info_utf16.len() <= max_len + 1 {
+            self.data.szInfo[..info_utf16.len()].copy_from_slice(&info_utf16);
+        } else {
+            self.data.szInfo[..max_len].copy_from_slice(&info_utf16[..max_len]);
+            self.data.szInfo[max_len] = 0;
+        }
+        self.flag(NIF_INFO)
+    }
+
+    /// Sets the balloon notification title.
+    ///
+    /// This is the title displayed above the body text of the balloon/toast
+    /// notification. The text is converted to UTF-16 format and truncated if
+    /// it exceeds the maximum length. Automatically sets the [NIF_INFO] flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The balloon notification title as any type that can be
+    ///   converted into a String
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    pub fn info_title(mut self, s: impl Into<String>) -> Self {
+        let s = s.into();
+        let title_utf16 = s.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
+        let max_len = self.data.szInfoTitle.len() - 1;
+        if title_utf16.len() <= max_len + 1 {
+            self.data.szInfoTitle[..title_utf16.len()].copy_from_slice(&title_utf16);
+        } else {
+            self.data.szInfoTitle[..max_len].copy_from_slice(&title_utf16[..max_len]);
+            self.data.szInfoTitle[max_len] = 0;
+        }
+        self.flag(NIF_INFO)
+    }
+
+    /// Sets the icon and behavior flags for the balloon notification.
+    ///
+    /// This accepts the `NIIF_*` values: [`NIIF_NONE`](windows::Win32::UI::Shell::NIIF_NONE),
+    /// [`NIIF_INFO`](windows::Win32::UI::Shell::NIIF_INFO),
+    /// [`NIIF_WARNING`](windows::Win32::UI::Shell::NIIF_WARNING), and
+    /// [`NIIF_ERROR`](windows::Win32::UI::Shell::NIIF_ERROR) select one of the
+    /// standard icons, while [`NIIF_USER`](windows::Win32::UI::Shell::NIIF_USER)
+    /// uses the icon set via [`NotifyIcon::balloon_icon`] instead. The
+    /// OR-able modifiers [`NIIF_NOSOUND`](windows::Win32::UI::Shell::NIIF_NOSOUND)
+    /// and [`NIIF_LARGE_ICON`](windows::Win32::UI::Shell::NIIF_LARGE_ICON) can
+    /// be combined with any of the above.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - A [`NOTIFY_ICON_INFOTIP_FLAGS`] value written into
+    ///   [`NOTIFYICONDATAW::dwInfoFlags`]
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    pub fn info_flags(mut self, flags: NOTIFY_ICON_INFOTIP_FLAGS) -> Self {
+        self.data.dwInfoFlags = flags;
+        self
+    }
+
+    /// Sets the icon state and which bits of it are significant.
+    ///
+    /// `state` holds the bits being set, while `mask` selects which of those
+    /// bits are actually applied; bits outside `mask` are left untouched.
+    /// This lets a caller flip a single bit — for example toggling
+    /// [`NIS_HIDDEN`](windows::Win32::UI::Shell::NIS_HIDDEN) to temporarily
+    /// hide the icon without deleting it, or setting
+    /// [`NIS_SHAREDICON`](windows::Win32::UI::Shell::NIS_SHAREDICON) so the
+    /// shared icon is not freed when the icon is deleted — by
+    /// sending [`NIM_MODIFY`] with only that bit present in the mask, instead
+    /// of repeatedly adding and deleting the icon. Automatically sets the
+    /// [NIF_STATE] flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The [`NOTIFY_ICON_STATE`] bits to set, written into
+    ///   [`NOTIFYICONDATAW::dwState`]
+    /// * `mask` - The [`NOTIFY_ICON_STATE`] bits that are significant in
+    ///   `state`, written into [`NOTIFYICONDATAW::dwStateMask`]
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    pub fn state(mut self, state: NOTIFY_ICON_STATE, mask: NOTIFY_ICON_STATE) -> Self {
+        self.data.dwState = state;
+        self.data.dwStateMask = mask;
+        self.flag(NIF_STATE)
+    }
+
     /// Sets the callback message identifier for the notification icon.
     ///
     /// When the user interacts with the notification icon (clicks,
@@ -301,12 +431,42 @@ impl NotifyIcon {
         self.flag(NIF_MESSAGE)
     }
 
+    /// Sets the application-defined identifier for the notification icon.
+    ///
+    /// Together with [`NotifyIcon::window_handle`], this forms the
+    /// `(hWnd, uID)` pair the Shell uses to identify which icon a message
+    /// refers to. Set a distinct `uid` for each icon a single window owns so
+    /// that [`NotifyIcon::notify_modify`] and [`NotifyIcon::notify_delete`]
+    /// target the right one instead of colliding on the default value of 0.
+    ///
+    /// This field is ignored when [`NotifyIcon::guid`] is used instead: the
+    /// Shell identifies icons either by `(hWnd, uID)` or by `guidItem`, never
+    /// both, so pick one scheme per icon.
+    ///
+    /// # Arguments
+    ///
+    /// * `uid` - The application-defined identifier written into
+    ///   [`NOTIFYICONDATAW::uID`]
+    ///
+    /// # Returns
+    ///
+    /// Self for method chaining
+    pub fn uid(mut self, uid: u32) -> Self {
+        self.data.uID = uid;
+        self
+    }
+
     /// Sets a GUID for the notification icon.
     ///
     /// The GUID provides a unique identifier for the notification icon, which
     /// can be useful for maintaining icon state across application
     /// restarts. Automatically sets the [NIF_GUID] flag.
     ///
+    /// Identifying icons by `guidItem` (set here) and by `(hWnd, uID)` (see
+    /// [`NotifyIcon::uid`]) are mutually exclusive schemes; prefer a GUID for
+    /// icons that should persist across restarts, and `uid` when a window
+    /// hosts several icons without needing that persistence.
+    ///
     /// # Arguments
     ///
     /// * `guid` - A 128-bit unsigned integer representing the GUID
@@ -481,4 +641,274 @@ impl NotifyIcon {
     pub fn notify_set_version(&self) -> windows::core::Result<()> {
         self.notify(NIM_SETVERSION)
     }
+
+    /// Retrieves the on-screen rectangle of the notification icon.
+    ///
+    /// This wraps [`Shell_NotifyIconGetRect`], building the
+    /// [`NOTIFYICONIDENTIFIER`] it requires from the same `(hWnd, uID)` or
+    /// `guidItem` fields already stored in this icon's data (see
+    /// [`NotifyIcon::window_handle`], [`NotifyIcon::uid`], and
+    /// [`NotifyIcon::guid`]). The result is useful for anchoring a context
+    /// menu to the icon, since the tray position varies across
+    /// multi-monitor and high-DPI setups.
+    ///
+    /// # Returns
+    ///
+    /// A [`windows::core::Result<RECT>`] with the icon's screen rectangle
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Shell_NotifyIconGetRect` function fails
+    pub fn get_rect(&self) -> windows::core::Result<RECT> {
+        let identifier = NOTIFYICONIDENTIFIER {
+            cbSize: std::mem::size_of::<NOTIFYICONIDENTIFIER>() as _,
+            hWnd: self.data.hWnd,
+            uID: self.data.uID,
+            guidItem: self.data.guidItem,
+        };
+        unsafe { Shell_NotifyIconGetRect(&identifier) }
+    }
+
+    /// Decodes a callback message received by the window procedure under
+    /// `NOTIFYICON_VERSION_4` behavior (see [`NotifyIcon::version`]).
+    ///
+    /// Under this behavior, the low word of `lparam` is the event code and
+    /// the high word is the icon's `uID`, while `wparam` packs the anchor
+    /// point as the low-word X and high-word Y screen coordinates. The event
+    /// code is either a standard mouse message (`WM_CONTEXTMENU`,
+    /// `WM_LBUTTONUP`, `WM_MOUSEMOVE`, etc.), surfaced as
+    /// [`TrayEventKind::Message`], or one of the `NIN_*` notifications.
+    ///
+    /// For version-0 (legacy) behavior, use
+    /// [`NotifyIcon::parse_callback_legacy`] instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `wparam` - The `WPARAM` the window procedure received
+    /// * `lparam` - The `LPARAM` the window procedure received
+    ///
+    /// # Returns
+    ///
+    /// The decoded [`TrayEvent`]
+    pub fn parse_callback(wparam: WPARAM, lparam: LPARAM) -> TrayEvent {
+        let lparam = lparam.0 as u32;
+        let event_code = lparam & 0xFFFF;
+        let uid = (lparam >> 16) & 0xFFFF;
+
+        let wparam = wparam.0 as u32;
+        let x = (wparam & 0xFFFF) as u16 as i16 as i32;
+        let y = ((wparam >> 16) & 0xFFFF) as u16 as i16 as i32;
+
+        let kind = match event_code {
+            code if code == NIN_SELECT => TrayEventKind::Select,
+            code if code == NIN_KEYSELECT => TrayEventKind::KeySelect,
+            code if code == NIN_BALLOONSHOW => TrayEventKind::BalloonShow,
+            code if code == NIN_BALLOONHIDE => TrayEventKind::BalloonHide,
+            code if code == NIN_BALLOONTIMEOUT => TrayEventKind::BalloonTimeout,
+            code if code == NIN_BALLOONUSERCLICK => TrayEventKind::BalloonUserClick,
+            code if code == NIN_POPUPOPEN => TrayEventKind::PopupOpen,
+            code if code == NIN_POPUPCLOSE => TrayEventKind::PopupClose,
+            message => TrayEventKind::Message(message),
+        };
+
+        TrayEvent {
+            kind,
+            uid,
+            point: Some((x, y)),
+        }
+    }
+
+    /// Decodes a callback message received by the window procedure under
+    /// legacy (version-0) behavior.
+    ///
+    /// Under legacy behavior, `wparam` is the icon's `uID` and `lparam` is
+    /// the raw mouse message, with no anchor point available. Use
+    /// [`NotifyIcon::parse_callback`] for `NOTIFYICON_VERSION_4` behavior
+    /// instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `wparam` - The `WPARAM` the window procedure received
+    /// * `lparam` - The `LPARAM` the window procedure received
+    ///
+    /// # Returns
+    ///
+    /// The decoded [`TrayEvent`], with [`TrayEvent::point`] always `None`
+    pub fn parse_callback_legacy(wparam: WPARAM, lparam: LPARAM) -> TrayEvent {
+        TrayEvent {
+            kind: TrayEventKind::Message(lparam.0 as u32),
+            uid: wparam.0 as u32,
+            point: None,
+        }
+    }
+}
+
+/// The kind of interaction decoded from a notification icon callback
+/// message.
+///
+/// See [`NotifyIcon::parse_callback`] and [`NotifyIcon::parse_callback_legacy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayEventKind {
+    /// A standard window message forwarded from the icon, such as
+    /// `WM_LBUTTONUP` or `WM_CONTEXTMENU`.
+    Message(u32),
+    /// `NIN_SELECT`: the icon was selected with the mouse, or with the
+    /// keyboard via Enter or Space.
+    Select,
+    /// `NIN_KEYSELECT`: the icon was selected via the keyboard using an
+    /// arrow key.
+    KeySelect,
+    /// `NIN_BALLOONSHOW`: the balloon notification was shown.
+    BalloonShow,
+    /// `NIN_BALLOONHIDE`: the balloon notification was hidden, other than by
+    /// timeout or user click.
+    BalloonHide,
+    /// `NIN_BALLOONTIMEOUT`: the balloon notification timed out.
+    BalloonTimeout,
+    /// `NIN_BALLOONUSERCLICK`: the user clicked the balloon notification.
+    BalloonUserClick,
+    /// `NIN_POPUPOPEN`: the user hovered over the icon; a rich pop-up
+    /// tooltip should be shown.
+    PopupOpen,
+    /// `NIN_POPUPCLOSE`: the rich pop-up tooltip should be closed.
+    PopupClose,
+}
+
+/// A notification icon callback event decoded by [`NotifyIcon::parse_callback`]
+/// or [`NotifyIcon::parse_callback_legacy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrayEvent {
+    /// The kind of interaction that occurred.
+    pub kind: TrayEventKind,
+    /// The `uID` of the icon that raised the event (see [`NotifyIcon::uid`]).
+    pub uid: u32,
+    /// The screen coordinates of the anchor point, populated under
+    /// `NOTIFYICON_VERSION_4` behavior. `None` when decoded via
+    /// [`NotifyIcon::parse_callback_legacy`].
+    pub point: Option<(i32, i32)>,
+}
+
+/// An RAII guard that adds a [`NotifyIcon`] to the system tray and
+/// guarantees its removal.
+///
+/// [`NotifyIconGuard::new`] sends [`NIM_ADD`] immediately; the icon is then
+/// removed with [`NIM_DELETE`] when the guard is dropped, so it disappears
+/// even if the owning object goes away during an unwind or the caller simply
+/// forgets to call [`NotifyIcon::notify_delete`]. The wrapped icon is still
+/// reachable through [`Deref`](std::ops::Deref) for read-only calls like
+/// [`NotifyIcon::notify_modify`] while the guard is alive; use
+/// [`NotifyIconGuard::modify`] to change a field and re-send in one step,
+/// since the builder methods consume `self` and so cannot be called through
+/// a reference. Call [`NotifyIconGuard::forget`] to opt out of the automatic
+/// delete, for example when a GUID-persisted icon is meant to survive the
+/// guard.
+///
+/// # Examples
+///
+/// ```rust,no_run,ignore
+/// let mut guard = NotifyIconGuard::new(
+///     NotifyIcon::new()
+///         .window_handle(hwnd)
+///         .tip("My Application")
+///         .icon(icon_handle),
+/// )?;
+///
+/// guard.modify(|icon| icon.tip("Updated tooltip text"))?;
+/// // `NIM_DELETE` is sent automatically when `guard` is dropped.
+/// ```
+pub struct NotifyIconGuard {
+    /// The wrapped icon, or `None` once it has been added to the tray and
+    /// then consumed by [`NotifyIconGuard::forget`].
+    icon: Option<NotifyIcon>,
+}
+
+impl NotifyIconGuard {
+    /// Adds `icon` to the system tray and returns a guard that removes it on
+    /// drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `icon` - The configured [`NotifyIcon`] to add
+    ///
+    /// # Returns
+    ///
+    /// A [`NotifyIconGuard`] that owns `icon` and will delete it on drop
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`NotifyIcon::notify_add`] fails
+    pub fn new(icon: NotifyIcon) -> windows::core::Result<Self> {
+        icon.notify_add()?;
+        Ok(Self { icon: Some(icon) })
+    }
+
+    /// Consumes the guard and returns the wrapped [`NotifyIcon`] without
+    /// deleting it.
+    ///
+    /// Use this when the icon should outlive the guard, for example a
+    /// GUID-persisted icon that should remain in the tray after the guard
+    /// goes out of scope.
+    ///
+    /// # Returns
+    ///
+    /// The wrapped [`NotifyIcon`], still added to the system tray
+    pub fn forget(mut self) -> NotifyIcon {
+        self.icon
+            .take()
+            .expect("icon is only taken here, which consumes the guard")
+    }
+
+    /// Rebuilds the wrapped icon and re-sends it with [`NIM_MODIFY`].
+    ///
+    /// Since the builder methods on [`NotifyIcon`] (such as
+    /// [`NotifyIcon::tip`]) consume `self`, they cannot be called through
+    /// [`Deref`](std::ops::Deref)'s `&NotifyIcon`. This method passes a copy
+    /// of the icon by value to `f` for reconfiguration, sends [`NIM_MODIFY`]
+    /// with the result, and stores the result back so the guard keeps owning
+    /// it. If `f` panics, the guard still holds the original icon and deletes
+    /// it on drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - A closure that reconfigures the icon, typically by chaining
+    ///   builder methods
+    ///
+    /// # Returns
+    ///
+    /// A [`windows::core::Result<()>`] indicating success or failure
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`NotifyIcon::notify_modify`] fails
+    pub fn modify(
+        &mut self,
+        f: impl FnOnce(NotifyIcon) -> NotifyIcon,
+    ) -> windows::core::Result<()> {
+        let current = self
+            .icon
+            .as_mut()
+            .expect("icon is only taken by forget(), which consumes the guard");
+        let icon = f(NotifyIcon { data: current.data });
+        let result = icon.notify_modify();
+        *current = icon;
+        result
+    }
+}
+
+impl std::ops::Deref for NotifyIconGuard {
+    type Target = NotifyIcon;
+
+    fn deref(&self) -> &Self::Target {
+        self.icon
+            .as_ref()
+            .expect("icon is only taken by forget(), which consumes the guard")
+    }
+}
+
+impl Drop for NotifyIconGuard {
+    fn drop(&mut self) {
+        if let Some(icon) = self.icon.take() {
+            let _ = icon.notify_delete();
+        }
+    }
 }